@@ -0,0 +1,214 @@
+//! Benchmarking setup for pallet-subnet.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_support::traits::Currency;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Zero;
+
+fn fund<T: Config>(who: &T::AccountId) {
+    let _ = T::Currency::make_free_balance_be(who, T::ProviderBond::get() * 100u32.into());
+}
+
+benchmarks! {
+    create_subnet {
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller.clone()), Vec::new(), Vec::new(), 0, 0, 0, Vec::new())
+    verify {
+        assert!(Subnets::<T>::contains_key(&caller));
+    }
+
+    register_provider {
+        // `p` is the number of providers already registered against the subnet.
+        let p in 0 .. T::MaxProvidersPerSubnet::get() - 1;
+
+        let owner: T::AccountId = whitelisted_caller();
+        Subnets::<T>::insert(&owner, SubnetMetadata::default());
+
+        let mut providers = BoundedVec::default();
+        for _ in 0 .. p {
+            providers.try_push(ProviderMetadata::default()).unwrap();
+        }
+        Providers::<T>::insert(&owner, providers);
+
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+    }: _(RawOrigin::Signed(caller.clone()), owner.clone(), Vec::new(), Vec::new(), p)
+    verify {
+        assert_eq!(Providers::<T>::get(&owner).map(|p| p.len()).unwrap_or(0), (p + 1) as usize);
+    }
+
+    deregister_provider {
+        let p in 0 .. T::MaxProvidersPerSubnet::get() - 1;
+
+        let owner: T::AccountId = whitelisted_caller();
+        Subnets::<T>::insert(&owner, SubnetMetadata::default());
+
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+
+        let mut providers = BoundedVec::default();
+        for _ in 0 .. p {
+            providers.try_push(ProviderMetadata::default()).unwrap();
+        }
+        providers.try_push(ProviderMetadata { provider: caller.clone(), ..Default::default() }).unwrap();
+        Providers::<T>::insert(&owner, providers);
+    }: _(RawOrigin::Signed(caller.clone()), owner.clone(), p + 1)
+    verify {
+        assert!(Unbonding::<T>::contains_key(&owner, &caller));
+    }
+
+    withdraw_unbonded {
+        let owner: T::AccountId = whitelisted_caller();
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        T::Currency::reserve(&caller, T::ProviderBond::get())?;
+        Unbonding::<T>::insert(&owner, &caller, (T::ProviderBond::get(), frame_system::Pallet::<T>::block_number()));
+    }: _(RawOrigin::Signed(caller.clone()), owner.clone())
+    verify {
+        assert!(!Unbonding::<T>::contains_key(&owner, &caller));
+    }
+
+    slash_provider {
+        let p in 0 .. T::MaxProvidersPerSubnet::get() - 1;
+
+        let owner: T::AccountId = whitelisted_caller();
+        Subnets::<T>::insert(&owner, SubnetMetadata::default());
+
+        let provider: T::AccountId = whitelisted_caller();
+        fund::<T>(&provider);
+        T::Currency::reserve(&provider, T::ProviderBond::get())?;
+
+        let mut providers = BoundedVec::default();
+        for _ in 0 .. p {
+            providers.try_push(ProviderMetadata::default()).unwrap();
+        }
+        providers
+            .try_push(ProviderMetadata { provider: provider.clone(), bond: T::ProviderBond::get(), ..Default::default() })
+            .unwrap();
+        Providers::<T>::insert(&owner, providers);
+    }: _(RawOrigin::Signed(owner.clone()), owner.clone(), provider.clone(), T::ProviderBond::get(), p + 1)
+    verify {
+        assert_eq!(Providers::<T>::get(&owner).unwrap()[p as usize].bond, 0u32.into());
+    }
+
+    report_performance {
+        let owner: T::AccountId = whitelisted_caller();
+        Subnets::<T>::insert(&owner, SubnetMetadata::default());
+
+        let caller: T::AccountId = whitelisted_caller();
+        let mut providers = BoundedVec::default();
+        providers.try_push(ProviderMetadata { provider: caller.clone(), ..Default::default() }).unwrap();
+        Providers::<T>::insert(&owner, providers);
+    }: _(RawOrigin::Signed(caller.clone()), owner.clone(), 100, 100)
+    verify {
+        assert_eq!(ProviderPerformance::<T>::get(&owner, &caller), (100, 100));
+    }
+
+    settle_subnet {
+        // `p` is the number of providers sharing the subnet's reward pot.
+        let p in 1 .. T::MaxProvidersPerSubnet::get();
+
+        let owner: T::AccountId = whitelisted_caller();
+        Subnets::<T>::insert(&owner, SubnetMetadata { rewards_allocation: 1_000_000, ..Default::default() });
+
+        let mut providers = BoundedVec::default();
+        for i in 0 .. p {
+            let provider: T::AccountId = frame_benchmarking::account("provider", i, 0);
+            providers.try_push(ProviderMetadata { provider: provider.clone(), ..Default::default() }).unwrap();
+            ProviderPerformance::<T>::insert(&owner, &provider, (1, 1));
+        }
+        Providers::<T>::insert(&owner, providers);
+
+        let now = frame_system::Pallet::<T>::block_number();
+        let subnet = Subnets::<T>::get(&owner).unwrap();
+    }: {
+        crate::Pallet::<T>::settle_subnet(&owner, &subnet, now);
+    }
+    verify {
+        assert_eq!(LastSettlement::<T>::get(&owner), now);
+    }
+
+    announce_key {
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller.clone()), Vec::new(), Vec::new())
+    verify {
+        assert!(ProviderKeys::<T>::contains_key(&caller, BoundedVec::default()));
+    }
+
+    revoke_key {
+        let caller: T::AccountId = whitelisted_caller();
+        let key_id: BoundedVec<u8, T::MaxKeyIdLen> = Default::default();
+        ProviderKeys::<T>::insert(&caller, &key_id, BoundedVec::<u8, T::MaxPublicKeyLen>::default());
+    }: _(RawOrigin::Signed(caller.clone()), Vec::new())
+    verify {
+        assert!(!ProviderKeys::<T>::contains_key(&caller, &key_id));
+        assert!(RevokedKeys::<T>::contains_key(&caller, &key_id));
+    }
+
+    list_capacity {
+        let caller: T::AccountId = whitelisted_caller();
+        let until = frame_system::Pallet::<T>::block_number() + 1_000u32.into();
+    }: _(RawOrigin::Signed(caller.clone()), 10, 1u32.into(), until)
+    verify {
+        assert!(CapacityListings::<T>::contains_key(&caller));
+    }
+
+    lease_capacity {
+        let provider: T::AccountId = whitelisted_caller();
+        let until = frame_system::Pallet::<T>::block_number() + 1_000u32.into();
+        CapacityListings::<T>::insert(&provider, CapacityListing {
+            cores: 10,
+            leased_cores: 0,
+            price_per_timeslice: 1u32.into(),
+            until,
+        });
+
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+    }: _(RawOrigin::Signed(caller.clone()), provider.clone(), 1, 10)
+    verify {
+        assert!(Leases::<T>::contains_key(&provider, &caller));
+    }
+
+    settle_lease {
+        let provider: T::AccountId = whitelisted_caller();
+        let consumer: T::AccountId = frame_benchmarking::account("consumer", 0, 0);
+        fund::<T>(&consumer);
+        T::Currency::reserve(&consumer, T::ProviderBond::get())?;
+
+        CapacityListings::<T>::insert(&provider, CapacityListing {
+            cores: 10,
+            leased_cores: 1,
+            price_per_timeslice: 1u32.into(),
+            until: frame_system::Pallet::<T>::block_number() + 1_000u32.into(),
+        });
+        Leases::<T>::insert(&provider, &consumer, Lease {
+            cores: 1,
+            price_per_timeslice: 1u32.into(),
+            remaining_timeslices: 1,
+            last_settled_at: Zero::zero(),
+        });
+
+        let now = frame_system::Pallet::<T>::block_number() + T::TimeslicePeriod::get();
+    }: {
+        Leases::<T>::mutate_exists(&provider, &consumer, |maybe_lease| {
+            if let Some(lease) = maybe_lease {
+                if crate::Pallet::<T>::settle_lease(&provider, &consumer, lease, now) && lease.remaining_timeslices == 0 {
+                    let freed_cores = lease.cores;
+                    *maybe_lease = None;
+                    CapacityListings::<T>::mutate(&provider, |maybe_listing| {
+                        if let Some(listing) = maybe_listing {
+                            listing.leased_cores = listing.leased_cores.saturating_sub(freed_cores);
+                        }
+                    });
+                }
+            }
+        });
+    }
+    verify {
+        assert!(!Leases::<T>::contains_key(&provider, &consumer));
+    }
+}
@@ -3,33 +3,90 @@
 // Export the pallet to make it accessible from the runtime.
 pub use pallet::*;
 
+pub mod weights;
+pub use weights::WeightInfo;
+
+pub mod pricing;
+pub use pricing::{Linear, PriceAdapter};
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 #[frame_support::pallet]
 pub mod pallet {
-    use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+    use frame_support::{
+        dispatch::DispatchResult,
+        pallet_prelude::*,
+        traits::{BalanceStatus, Currency, OnUnbalanced, ReservableCurrency},
+    };
     use frame_system::pallet_prelude::*;
+    use sp_runtime::{
+        traits::{Saturating, Zero},
+        Percent, SaturatedConversion,
+    };
     use sp_std::vec::Vec;
     use serde::{Deserialize, Serialize};
 
+    use crate::weights::WeightInfo;
+    use crate::pricing::PriceAdapter;
+
+    // Convenience alias for the pallet's currency's balance type.
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    // Convenience alias for the pallet's currency's negative-imbalance type.
+    pub type NegativeImbalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+
     // Structure to hold metadata for a subnet.
-    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, Default, Serialize, Deserialize)]
-    pub struct SubnetMetadata {
-        title: Vec<u8>,               // Title of the subnet
-        intro: Vec<u8>,               // Introduction or description of the subnet
-        rewards_allocation: u32,      // Rewards allocation for the subnet
-        core_performance: u32,        // Core performance metric
-        gpunet_performance: u32,      // GPU network performance metric
-        metadata: Vec<u8>,            // Additional metadata
+    #[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, DefaultNoBound, RuntimeDebugNoBound, Serialize, Deserialize)]
+    #[scale_info(skip_type_params(T))]
+    #[serde(bound = "")]
+    pub struct SubnetMetadata<T: Config> {
+        pub(super) title: BoundedVec<u8, T::MaxTitleLen>,       // Title of the subnet
+        pub(super) intro: BoundedVec<u8, T::MaxIntroLen>,       // Introduction or description of the subnet
+        pub(super) rewards_allocation: u32,                     // Rewards allocation for the subnet
+        pub(super) core_performance: u32,                       // Core performance metric
+        pub(super) gpunet_performance: u32,                     // GPU network performance metric
+        pub(super) metadata: BoundedVec<u8, T::MaxMetadataLen>, // Additional metadata
     }
 
     // Structure to hold metadata for a provider.
-    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, Default, Serialize, Deserialize)]
-    pub struct ProviderMetadata {
-        name: Vec<u8>,                // Name of the provider
-        resource_details: Vec<u8>,    // Details about the resources provided
+    #[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, DefaultNoBound, RuntimeDebugNoBound, Serialize, Deserialize)]
+    #[scale_info(skip_type_params(T))]
+    #[serde(bound = "")]
+    pub struct ProviderMetadata<T: Config> {
+        pub(super) provider: T::AccountId,               // Account that registered this provider
+        pub(super) name: BoundedVec<u8, T::MaxTitleLen>, // Name of the provider
+        pub(super) resource_details: BoundedVec<u8, T::MaxResourceDetailsLen>, // Details about the resources provided
+        pub(super) bond: BalanceOf<T>,                   // Amount currently reserved as this provider's bond
+    }
+
+    // A provider's published GPU capacity offering.
+    #[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, DefaultNoBound, RuntimeDebugNoBound, Serialize, Deserialize)]
+    #[scale_info(skip_type_params(T))]
+    #[serde(bound = "")]
+    pub struct CapacityListing<T: Config> {
+        pub(super) cores: u32,                    // Total cores offered
+        pub(super) leased_cores: u32,              // Cores currently leased out of `cores`
+        pub(super) price_per_timeslice: BalanceOf<T>, // Base price per core per timeslice
+        pub(super) until: T::BlockNumber,          // Block after which the listing expires
+    }
+
+    // An active lease of a provider's capacity by a consumer.
+    #[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, DefaultNoBound, RuntimeDebugNoBound, Serialize, Deserialize)]
+    #[scale_info(skip_type_params(T))]
+    #[serde(bound = "")]
+    pub struct Lease<T: Config> {
+        pub(super) cores: u32,                     // Cores leased
+        pub(super) price_per_timeslice: BalanceOf<T>, // Price per core per timeslice, locked in at lease time
+        pub(super) remaining_timeslices: u32,       // Timeslices left to settle
+        pub(super) last_settled_at: T::BlockNumber, // Block at which this lease was last settled
     }
 
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
     // Configuration trait for the pallet.
@@ -37,26 +94,233 @@ pub mod pallet {
     pub trait Config: frame_system::Config {
         // Event type used by the pallet.
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+        // Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+
+        // Maximum length of a subnet title.
+        #[pallet::constant]
+        type MaxTitleLen: Get<u32>;
+
+        // Maximum length of a subnet introduction.
+        #[pallet::constant]
+        type MaxIntroLen: Get<u32>;
+
+        // Maximum length of any free-form metadata blob.
+        #[pallet::constant]
+        type MaxMetadataLen: Get<u32>;
+
+        // Maximum length of a provider's resource details.
+        #[pallet::constant]
+        type MaxResourceDetailsLen: Get<u32>;
+
+        // Maximum number of providers a single subnet may register.
+        #[pallet::constant]
+        type MaxProvidersPerSubnet: Get<u32>;
+
+        // Currency used to bond providers.
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        // Amount a provider must reserve in order to register.
+        #[pallet::constant]
+        type ProviderBond: Get<BalanceOf<Self>>;
+
+        // Delay, in blocks, between a provider deregistering and its bond becoming withdrawable.
+        #[pallet::constant]
+        type UnbondingDelay: Get<Self::BlockNumber>;
+
+        // Length, in blocks, of a single reward settlement period.
+        #[pallet::constant]
+        type RewardPeriod: Get<Self::BlockNumber>;
+
+        // Maximum number of subnets examined for reward settlement in a single block's
+        // `on_initialize`, bounding that hook's weight as the number of subnets grows.
+        #[pallet::constant]
+        type MaxSubnetSettlementsPerBlock: Get<u32>;
+
+        // Weight given to a provider's core-performance score when computing its reward share.
+        #[pallet::constant]
+        type CoreWeight: Get<u32>;
+
+        // Weight given to a provider's GPU-network-performance score when computing its reward share.
+        #[pallet::constant]
+        type GpunetWeight: Get<u32>;
+
+        // Maximum length of a dispatch key identifier.
+        #[pallet::constant]
+        type MaxKeyIdLen: Get<u32>;
+
+        // Maximum length of an announced public key.
+        #[pallet::constant]
+        type MaxPublicKeyLen: Get<u32>;
+
+        // Length, in blocks, of a single billable timeslice in the capacity marketplace.
+        #[pallet::constant]
+        type TimeslicePeriod: Get<Self::BlockNumber>;
+
+        // Maximum number of leases examined for revenue settlement in a single block's
+        // `on_initialize`, bounding that hook's weight as the number of leases grows.
+        #[pallet::constant]
+        type MaxLeaseSettlementsPerBlock: Get<u32>;
+
+        // Pluggable pricing model used to price a timeslice of leased capacity.
+        type PriceAdapter: PriceAdapter<BalanceOf<Self>>;
+
+        // Slope applied to listing utilization by the configured `PriceAdapter`.
+        #[pallet::constant]
+        type PriceSlope: Get<BalanceOf<Self>>;
+
+        // Share of settled lease revenue, in parts per hundred, routed to `OnRevenue`
+        // instead of the provider.
+        #[pallet::constant]
+        type ProtocolFeePercent: Get<Percent>;
+
+        // Handler for the protocol's cut of settled lease revenue.
+        type OnRevenue: OnUnbalanced<NegativeImbalanceOf<Self>>;
     }
 
     // Storage map to store subnets, keyed by the account ID of the subnet owner.
     #[pallet::storage]
     #[pallet::getter(fn subnets)]
-    pub type Subnets<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, SubnetMetadata, OptionQuery>;
+    pub type Subnets<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, SubnetMetadata<T>, OptionQuery>;
 
     // Storage map to store providers, keyed by the account ID of the subnet owner.
     #[pallet::storage]
     #[pallet::getter(fn providers)]
-    pub type Providers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Vec<ProviderMetadata>, ValueQuery>;
+    pub type Providers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<ProviderMetadata<T>, T::MaxProvidersPerSubnet>,
+        OptionQuery,
+    >;
+
+    // Storage double map tracking bonds that have been queued for unbonding, keyed by the
+    // subnet owner and then the provider account. Becomes withdrawable once the current
+    // block number reaches the stored unlock block.
+    #[pallet::storage]
+    #[pallet::getter(fn unbonding)]
+    pub type Unbonding<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        (BalanceOf<T>, T::BlockNumber),
+        OptionQuery,
+    >;
+
+    // Per-provider performance scores, as last reported via `report_performance`, keyed by
+    // the subnet owner and then the provider account.
+    #[pallet::storage]
+    #[pallet::getter(fn provider_performance)]
+    pub type ProviderPerformance<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        (u32, u32),
+        ValueQuery,
+    >;
+
+    // Block number at which a subnet's reward period was last settled.
+    #[pallet::storage]
+    #[pallet::getter(fn last_settlement)]
+    pub type LastSettlement<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber, ValueQuery>;
+
+    // Reward amount left over from a subnet's last settlement due to integer-division
+    // rounding, carried forward so it isn't lost.
+    #[pallet::storage]
+    #[pallet::getter(fn reward_remainder)]
+    pub type RewardRemainder<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    // Directory of a provider's current encryption/signing public keys, keyed by the
+    // provider account and then a caller-chosen key identifier.
+    #[pallet::storage]
+    #[pallet::getter(fn provider_keys)]
+    pub type ProviderKeys<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxKeyIdLen>,
+        BoundedVec<u8, T::MaxPublicKeyLen>,
+        OptionQuery,
+    >;
+
+    // Key identifiers a provider has revoked, recording the block the revocation took
+    // effect so off-chain dispatchers can tell a key is stale and stop routing work to it.
+    #[pallet::storage]
+    #[pallet::getter(fn revoked_keys)]
+    pub type RevokedKeys<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxKeyIdLen>,
+        T::BlockNumber,
+        OptionQuery,
+    >;
+
+    // A provider's current capacity listing, if any.
+    #[pallet::storage]
+    #[pallet::getter(fn capacity_listings)]
+    pub type CapacityListings<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, CapacityListing<T>, OptionQuery>;
+
+    // Active leases, keyed by the provider and then the leasing consumer.
+    #[pallet::storage]
+    #[pallet::getter(fn leases)]
+    pub type Leases<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        Lease<T>,
+        OptionQuery,
+    >;
+
+    // Raw storage key after which the next `on_initialize` subnet settlement scan resumes,
+    // so the bounded scan advances round-robin across `Subnets` instead of always starting
+    // (and running out of budget) at the same fixed prefix. `None` means start from the
+    // beginning of the map.
+    #[pallet::storage]
+    pub type SubnetSettlementCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+    // Raw storage key after which the next `on_initialize` lease settlement scan resumes;
+    // see `SubnetSettlementCursor` for why this is needed.
+    #[pallet::storage]
+    pub type LeaseSettlementCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
 
     // Events emitted by the pallet.
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         // Event emitted when a subnet is created.
-        SubnetCreated(T::AccountId, SubnetMetadata),
+        SubnetCreated(T::AccountId, SubnetMetadata<T>),
         // Event emitted when a provider is registered.
-        ProviderRegistered(T::AccountId, ProviderMetadata),
+        ProviderRegistered(T::AccountId, ProviderMetadata<T>),
+        // Event emitted when a provider's bond is reserved.
+        ProviderBonded(T::AccountId, BalanceOf<T>),
+        // Event emitted when a portion of a provider's bond is slashed.
+        ProviderSlashed(T::AccountId, BalanceOf<T>),
+        // Event emitted when a provider's bond is unreserved after the unbonding delay.
+        ProviderUnbonded(T::AccountId, BalanceOf<T>),
+        // Event emitted when a provider reports a new performance score.
+        PerformanceReported(T::AccountId, T::AccountId, u32, u32),
+        // Event emitted when a subnet's rewards are distributed at a period boundary.
+        RewardsDistributed(T::AccountId, BalanceOf<T>),
+        // Event emitted when a provider announces a dispatch key.
+        KeyAnnounced(T::AccountId, BoundedVec<u8, T::MaxKeyIdLen>, BoundedVec<u8, T::MaxPublicKeyLen>),
+        // Event emitted when a provider revokes a previously announced dispatch key.
+        KeyRevoked(T::AccountId, BoundedVec<u8, T::MaxKeyIdLen>),
+        // Event emitted when a provider lists GPU capacity for lease.
+        CapacityListed(T::AccountId, u32, BalanceOf<T>, T::BlockNumber),
+        // Event emitted when a consumer leases a provider's capacity.
+        CapacityLeased(T::AccountId, T::AccountId, u32, u32, BalanceOf<T>),
+        // Event emitted when a lease's revenue is settled at a timeslice boundary.
+        RevenueSettled(T::AccountId, T::AccountId, BalanceOf<T>),
     }
 
     // Errors that can occur within the pallet.
@@ -65,16 +329,34 @@ pub mod pallet {
         SubnetAlreadyExists,          // Error when a subnet already exists for the account
         ProviderAlreadyRegistered,    // Error when a provider is already registered
         SubnetNotFound,               // Error when the specified subnet is not found
+        TooLong,                      // Error when a bounded field exceeds its configured maximum length
+        TooManyProviders,             // Error when a subnet already has the maximum number of providers
+        ProviderNotFound,             // Error when the caller has no provider registered against the subnet
+        NotSubnetOwner,               // Error when a non-owner, non-root origin attempts an owner-only action
+        NothingToWithdraw,            // Error when there is no queued unbonding for the caller
+        UnbondingNotDue,              // Error when the unbonding delay has not yet elapsed
+        KeyNotFound,                  // Error when the specified dispatch key does not exist for the caller
+        NoSuchListing,                // Error when the specified provider has no capacity listing
+        ListingExpired,               // Error when a listing's `until` block has already passed
+        InsufficientCapacity,         // Error when a listing does not have enough free cores for the request
+        LeaseAlreadyExists,           // Error when the caller already has an active lease against this provider
+        ProviderCountHintTooLow,      // Error when the caller-supplied provider count hint understates the actual count
+        UnbondingAlreadyQueued,       // Error when the caller already has a bond queued for unbonding against this subnet
     }
 
     // Dispatchable functions (extrinsics) for the pallet.
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         // Function to create a new subnet.
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::create_subnet())]
         pub fn create_subnet(
             origin: OriginFor<T>,
-            metadata: SubnetMetadata,
+            title: Vec<u8>,
+            intro: Vec<u8>,
+            rewards_allocation: u32,
+            core_performance: u32,
+            gpunet_performance: u32,
+            metadata: Vec<u8>,
         ) -> DispatchResult {
             // Ensure the caller is a signed account.
             let who = ensure_signed(origin)?;
@@ -82,20 +364,41 @@ pub mod pallet {
             // Ensure the subnet does not already exist for the caller.
             ensure!(!Subnets::<T>::contains_key(&who), Error::<T>::SubnetAlreadyExists);
 
+            // Bound every free-form field, rejecting inputs that exceed the configured limits.
+            let title: BoundedVec<u8, T::MaxTitleLen> =
+                title.try_into().map_err(|_| Error::<T>::TooLong)?;
+            let intro: BoundedVec<u8, T::MaxIntroLen> =
+                intro.try_into().map_err(|_| Error::<T>::TooLong)?;
+            let metadata: BoundedVec<u8, T::MaxMetadataLen> =
+                metadata.try_into().map_err(|_| Error::<T>::TooLong)?;
+
+            let subnet_metadata = SubnetMetadata {
+                title,
+                intro,
+                rewards_allocation,
+                core_performance,
+                gpunet_performance,
+                metadata,
+            };
+
             // Insert the subnet metadata into storage.
-            Subnets::<T>::insert(&who, metadata.clone());
+            Subnets::<T>::insert(&who, subnet_metadata.clone());
 
             // Emit an event indicating the subnet was created.
-            Self::deposit_event(Event::SubnetCreated(who, metadata));
+            Self::deposit_event(Event::SubnetCreated(who, subnet_metadata));
             Ok(())
         }
 
-        // Function to register a provider for a subnet.
-        #[pallet::weight(10_000)]
+        // Function to register a provider for a subnet. `provider_count_hint` must be at
+        // least the subnet's current provider count, and is used (rather than a storage
+        // read) to compute this call's weight ahead of dispatch.
+        #[pallet::weight(T::WeightInfo::register_provider(*provider_count_hint))]
         pub fn register_provider(
             origin: OriginFor<T>,
             subnet_owner: T::AccountId,
-            provider_metadata: ProviderMetadata,
+            name: Vec<u8>,
+            resource_details: Vec<u8>,
+            provider_count_hint: u32,
         ) -> DispatchResult {
             // Ensure the caller is a signed account.
             let who = ensure_signed(origin)?;
@@ -103,14 +406,543 @@ pub mod pallet {
             // Ensure the specified subnet exists.
             ensure!(Subnets::<T>::contains_key(&subnet_owner), Error::<T>::SubnetNotFound);
 
-            // Add the provider metadata to the list of providers for the subnet.
-            Providers::<T>::mutate(&subnet_owner, |providers| {
-                providers.push(provider_metadata.clone());
-            });
+            // The hint must cover the subnet's actual provider count, so the weight charged
+            // above reflects (at least) the real cost of this dispatch.
+            let actual_count = Providers::<T>::decode_len(&subnet_owner).unwrap_or(0) as u32;
+            ensure!(actual_count <= provider_count_hint, Error::<T>::ProviderCountHintTooLow);
+
+            // Ensure the caller isn't already registered against this subnet. Without this,
+            // an account could repeatedly re-register, reserving a fresh bond each time and
+            // collecting a separate reward share per registration at settlement.
+            ensure!(
+                !Providers::<T>::get(&subnet_owner)
+                    .unwrap_or_default()
+                    .iter()
+                    .any(|p| p.provider == who),
+                Error::<T>::ProviderAlreadyRegistered
+            );
+
+            // Bound the provider's fields, rejecting inputs that exceed the configured limits.
+            let name: BoundedVec<u8, T::MaxTitleLen> =
+                name.try_into().map_err(|_| Error::<T>::TooLong)?;
+            let resource_details: BoundedVec<u8, T::MaxResourceDetailsLen> =
+                resource_details.try_into().map_err(|_| Error::<T>::TooLong)?;
+
+            // Reserve the provider bond from the caller's balance, giving the network
+            // sybil resistance against accounts registering to spam the provider list.
+            let bond = T::ProviderBond::get();
+            T::Currency::reserve(&who, bond)?;
+
+            let provider_metadata = ProviderMetadata { provider: who.clone(), name, resource_details, bond };
+
+            // Add the provider metadata to the list of providers for the subnet,
+            // rejecting registration once the subnet is at its provider cap.
+            if let Err(e) = Providers::<T>::try_mutate(&subnet_owner, |providers| -> DispatchResult {
+                let providers = providers.get_or_insert_with(BoundedVec::default);
+                providers
+                    .try_push(provider_metadata.clone())
+                    .map_err(|_| Error::<T>::TooManyProviders)?;
+                Ok(())
+            }) {
+                // Roll back the reserve if the provider could not be added.
+                T::Currency::unreserve(&who, bond);
+                return Err(e);
+            }
+
+            // Emit events for the registration and the bond reservation.
+            Self::deposit_event(Event::ProviderRegistered(who.clone(), provider_metadata));
+            Self::deposit_event(Event::ProviderBonded(who, bond));
+            Ok(())
+        }
+
+        // Function to deregister a provider from a subnet, queuing its bond for unbonding.
+        // `provider_count_hint` must be at least the subnet's current provider count, and is
+        // used (rather than a storage read) to compute this call's weight ahead of dispatch.
+        #[pallet::weight(T::WeightInfo::deregister_provider(*provider_count_hint))]
+        pub fn deregister_provider(
+            origin: OriginFor<T>,
+            subnet_owner: T::AccountId,
+            provider_count_hint: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let actual_count = Providers::<T>::decode_len(&subnet_owner).unwrap_or(0) as u32;
+            ensure!(actual_count <= provider_count_hint, Error::<T>::ProviderCountHintTooLow);
+
+            // A second queued unbonding for the same (subnet, provider) pair would silently
+            // overwrite the first, permanently stranding its bond with no path to withdraw
+            // it. The caller must withdraw the existing one first.
+            ensure!(
+                !Unbonding::<T>::contains_key(&subnet_owner, &who),
+                Error::<T>::UnbondingAlreadyQueued
+            );
+
+            let bond = Providers::<T>::try_mutate(&subnet_owner, |providers| -> Result<BalanceOf<T>, DispatchError> {
+                let providers = providers.as_mut().ok_or(Error::<T>::ProviderNotFound)?;
+                let index = providers
+                    .iter()
+                    .position(|p| p.provider == who)
+                    .ok_or(Error::<T>::ProviderNotFound)?;
+                Ok(providers.remove(index).bond)
+            })?;
+
+            // Queue the bond for release once the unbonding delay elapses.
+            let unlock_at = frame_system::Pallet::<T>::block_number() + T::UnbondingDelay::get();
+            Unbonding::<T>::insert(&subnet_owner, &who, (bond, unlock_at));
+            Ok(())
+        }
+
+        // Function to withdraw a bond once its unbonding delay has elapsed.
+        #[pallet::weight(T::WeightInfo::withdraw_unbonded())]
+        pub fn withdraw_unbonded(
+            origin: OriginFor<T>,
+            subnet_owner: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let (bond, unlock_at) =
+                Unbonding::<T>::get(&subnet_owner, &who).ok_or(Error::<T>::NothingToWithdraw)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= unlock_at,
+                Error::<T>::UnbondingNotDue
+            );
+
+            T::Currency::unreserve(&who, bond);
+            Unbonding::<T>::remove(&subnet_owner, &who);
+
+            Self::deposit_event(Event::ProviderUnbonded(who, bond));
+            Ok(())
+        }
+
+        // Function for a subnet owner (or root) to slash a portion of a provider's bond.
+        // `provider_count_hint` must be at least the subnet's current provider count, and is
+        // used (rather than a storage read) to compute this call's weight ahead of dispatch.
+        #[pallet::weight(T::WeightInfo::slash_provider(*provider_count_hint))]
+        pub fn slash_provider(
+            origin: OriginFor<T>,
+            subnet_owner: T::AccountId,
+            provider: T::AccountId,
+            amount: BalanceOf<T>,
+            provider_count_hint: u32,
+        ) -> DispatchResult {
+            // Either the subnet owner or root may slash a misbehaving provider.
+            match ensure_signed(origin.clone()) {
+                Ok(who) => ensure!(who == subnet_owner, Error::<T>::NotSubnetOwner),
+                Err(_) => ensure_root(origin)?,
+            }
+
+            let actual_count = Providers::<T>::decode_len(&subnet_owner).unwrap_or(0) as u32;
+            ensure!(actual_count <= provider_count_hint, Error::<T>::ProviderCountHintTooLow);
+
+            let slashed = Providers::<T>::try_mutate(&subnet_owner, |providers| -> Result<BalanceOf<T>, DispatchError> {
+                let providers = providers.as_mut().ok_or(Error::<T>::ProviderNotFound)?;
+                let entry = providers
+                    .iter_mut()
+                    .find(|p| p.provider == provider)
+                    .ok_or(Error::<T>::ProviderNotFound)?;
+
+                let to_slash = amount.min(entry.bond);
+                let (_imbalance, _remainder) = T::Currency::slash_reserved(&provider, to_slash);
+                entry.bond = entry.bond.saturating_sub(to_slash);
+                Ok(to_slash)
+            })?;
+
+            Self::deposit_event(Event::ProviderSlashed(provider, slashed));
+            Ok(())
+        }
+
+        // Function for a registered provider to report its own latest performance scores,
+        // which feed into the next reward settlement for the subnet.
+        #[pallet::weight(T::WeightInfo::report_performance())]
+        pub fn report_performance(
+            origin: OriginFor<T>,
+            subnet_owner: T::AccountId,
+            core_performance: u32,
+            gpunet_performance: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let providers = Providers::<T>::get(&subnet_owner).ok_or(Error::<T>::ProviderNotFound)?;
+            ensure!(providers.iter().any(|p| p.provider == who), Error::<T>::ProviderNotFound);
+
+            ProviderPerformance::<T>::insert(&subnet_owner, &who, (core_performance, gpunet_performance));
+
+            Self::deposit_event(Event::PerformanceReported(subnet_owner, who, core_performance, gpunet_performance));
+            Ok(())
+        }
+
+        // Function for a provider to announce (or update) the public key compute-job
+        // payloads destined for it should be encrypted against.
+        #[pallet::weight(T::WeightInfo::announce_key())]
+        pub fn announce_key(
+            origin: OriginFor<T>,
+            key_id: Vec<u8>,
+            public_key: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let key_id: BoundedVec<u8, T::MaxKeyIdLen> =
+                key_id.try_into().map_err(|_| Error::<T>::TooLong)?;
+            let public_key: BoundedVec<u8, T::MaxPublicKeyLen> =
+                public_key.try_into().map_err(|_| Error::<T>::TooLong)?;
+
+            ProviderKeys::<T>::insert(&who, &key_id, public_key.clone());
+            // Re-announcing under a previously revoked key id supersedes the revocation.
+            RevokedKeys::<T>::remove(&who, &key_id);
+
+            Self::deposit_event(Event::KeyAnnounced(who, key_id, public_key));
+            Ok(())
+        }
+
+        // Function for a provider to revoke a dispatch key, so off-chain dispatchers stop
+        // routing work encrypted against it.
+        #[pallet::weight(T::WeightInfo::revoke_key())]
+        pub fn revoke_key(origin: OriginFor<T>, key_id: Vec<u8>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let key_id: BoundedVec<u8, T::MaxKeyIdLen> =
+                key_id.try_into().map_err(|_| Error::<T>::TooLong)?;
+            ensure!(ProviderKeys::<T>::contains_key(&who, &key_id), Error::<T>::KeyNotFound);
+
+            ProviderKeys::<T>::remove(&who, &key_id);
+            RevokedKeys::<T>::insert(&who, &key_id, frame_system::Pallet::<T>::block_number());
+
+            Self::deposit_event(Event::KeyRevoked(who, key_id));
+            Ok(())
+        }
+
+        // Function for a provider to list (or replace) a GPU capacity offering.
+        #[pallet::weight(T::WeightInfo::list_capacity())]
+        pub fn list_capacity(
+            origin: OriginFor<T>,
+            cores: u32,
+            price_per_timeslice: BalanceOf<T>,
+            until: T::BlockNumber,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                until > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::ListingExpired
+            );
+
+            let leased_cores = CapacityListings::<T>::get(&who).map(|l| l.leased_cores).unwrap_or(0);
+            let listing = CapacityListing { cores, leased_cores, price_per_timeslice, until };
+            CapacityListings::<T>::insert(&who, listing);
+
+            Self::deposit_event(Event::CapacityListed(who, cores, price_per_timeslice, until));
+            Ok(())
+        }
+
+        // Function for a consumer to lease a slice of a provider's listed capacity,
+        // reserving payment for the full duration up front.
+        #[pallet::weight(T::WeightInfo::lease_capacity())]
+        pub fn lease_capacity(
+            origin: OriginFor<T>,
+            provider: T::AccountId,
+            cores: u32,
+            timeslices: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // A second lease against the same provider would silently overwrite the first
+            // in `Leases`, leaking the first lease's reserved currency (never unreserved or
+            // settled again) and double-counting its cores against `leased_cores`. The
+            // caller must let the existing lease run to completion first.
+            ensure!(!Leases::<T>::contains_key(&provider, &who), Error::<T>::LeaseAlreadyExists);
+
+            let mut listing = CapacityListings::<T>::get(&provider).ok_or(Error::<T>::NoSuchListing)?;
+            ensure!(
+                listing.until > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::ListingExpired
+            );
+            let available = listing.cores.saturating_sub(listing.leased_cores);
+            ensure!(cores <= available, Error::<T>::InsufficientCapacity);
+
+            // Price the lease against utilization including the cores being leased now.
+            let price = T::PriceAdapter::price(
+                listing.price_per_timeslice,
+                T::PriceSlope::get(),
+                listing.leased_cores.saturating_add(cores),
+                listing.cores,
+            );
+            let total_price = price
+                .saturating_mul((cores as u128).saturated_into())
+                .saturating_mul((timeslices as u128).saturated_into());
+
+            T::Currency::reserve(&who, total_price)?;
+
+            listing.leased_cores = listing.leased_cores.saturating_add(cores);
+            CapacityListings::<T>::insert(&provider, listing);
+
+            Leases::<T>::insert(
+                &provider,
+                &who,
+                Lease {
+                    cores,
+                    price_per_timeslice: price,
+                    remaining_timeslices: timeslices,
+                    last_settled_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
 
-            // Emit an event indicating the provider was registered.
-            Self::deposit_event(Event::ProviderRegistered(who, provider_metadata));
+            Self::deposit_event(Event::CapacityLeased(provider, who, cores, timeslices, price));
             Ok(())
         }
     }
+
+    // Splits `pot` across `scores` in proportion to each entry's share of the total score,
+    // using integer division. Returns each entry's payout (parallel to `scores`, zero where
+    // the entry itself scored zero or the total score is zero) and the amount left over due
+    // to rounding, which the caller should carry forward rather than lose. Kept as a plain
+    // function of primitives (no `T: Config`) so the rounding/remainder arithmetic can be
+    // unit tested directly, without a mock runtime.
+    fn split_reward_pot(pot: u128, scores: &[u64]) -> (Vec<u128>, u128) {
+        let total_score: u64 = scores.iter().fold(0u64, |a, b| a.saturating_add(*b));
+        if total_score == 0 {
+            return (scores.iter().map(|_| 0u128).collect(), pot);
+        }
+
+        let mut distributed = 0u128;
+        let payouts: Vec<u128> = scores
+            .iter()
+            .map(|score| {
+                let reward = pot.saturating_mul(*score as u128) / (total_score as u128);
+                distributed = distributed.saturating_add(reward);
+                reward
+            })
+            .collect();
+
+        (payouts, pot.saturating_sub(distributed))
+    }
+
+    #[cfg(test)]
+    mod reward_split_tests {
+        use super::split_reward_pot;
+
+        #[test]
+        fn splits_proportionally_and_carries_the_rounding_remainder() {
+            // 100 split 1:2:3 -> 16, 33, 50, with 1 left over from rounding.
+            let (payouts, remainder) = split_reward_pot(100, &[1, 2, 3]);
+            assert_eq!(payouts, vec![16, 33, 50]);
+            assert_eq!(remainder, 1);
+            assert_eq!(payouts.iter().sum::<u128>() + remainder, 100);
+        }
+
+        #[test]
+        fn zero_total_score_carries_the_whole_pot_forward() {
+            let (payouts, remainder) = split_reward_pot(100, &[0, 0, 0]);
+            assert_eq!(payouts, vec![0, 0, 0]);
+            assert_eq!(remainder, 100);
+        }
+
+        #[test]
+        fn zero_score_entries_get_nothing() {
+            let (payouts, remainder) = split_reward_pot(100, &[0, 1]);
+            assert_eq!(payouts, vec![0, 100]);
+            assert_eq!(remainder, 0);
+        }
+
+        #[test]
+        fn empty_scores_carries_the_whole_pot_forward() {
+            let (payouts, remainder) = split_reward_pot(100, &[]);
+            assert!(payouts.is_empty());
+            assert_eq!(remainder, 100);
+        }
+    }
+
+    impl<T: Config> Pallet<T>
+    where
+        BalanceOf<T>: From<u32>,
+    {
+        // Settles a single subnet's reward period: splits `rewards_allocation` (plus any
+        // remainder carried over from the previous period) across its providers in
+        // proportion to their weighted performance score, and mints the result to them.
+        pub(crate) fn settle_subnet(subnet_owner: &T::AccountId, subnet: &SubnetMetadata<T>, now: T::BlockNumber) -> Weight {
+            LastSettlement::<T>::insert(subnet_owner, now);
+
+            let providers = match Providers::<T>::get(subnet_owner) {
+                Some(providers) if !providers.is_empty() => providers,
+                _ => return T::DbWeight::get().reads_writes(1, 1),
+            };
+
+            // `register_provider` now rejects duplicate registrations, but score by
+            // distinct provider account (rather than by list entry) defensively, so a
+            // single account can never be paid more than one share of the pot per
+            // settlement even if the list were to end up with duplicate entries.
+            let mut seen = sp_std::collections::btree_set::BTreeSet::new();
+            let scores: Vec<(T::AccountId, u64)> = providers
+                .iter()
+                .filter(|p| seen.insert(p.provider.clone()))
+                .map(|p| {
+                    let (core, gpunet) = ProviderPerformance::<T>::get(subnet_owner, &p.provider);
+                    let score = (T::CoreWeight::get() as u64).saturating_mul(core as u64)
+                        .saturating_add((T::GpunetWeight::get() as u64).saturating_mul(gpunet as u64));
+                    (p.provider.clone(), score)
+                })
+                .collect();
+
+            let total_score: u64 = scores.iter().map(|(_, s)| *s).fold(0u64, |a, b| a.saturating_add(b));
+
+            let pot: BalanceOf<T> =
+                BalanceOf::<T>::from(subnet.rewards_allocation).saturating_add(RewardRemainder::<T>::get(subnet_owner));
+
+            if total_score == 0 || pot.is_zero() {
+                // Nothing to score against; carry the whole pot forward untouched.
+                RewardRemainder::<T>::insert(subnet_owner, pot);
+                return T::WeightInfo::settle_subnet(providers.len() as u32);
+            }
+
+            let pot_u128: u128 = pot.saturated_into();
+            let score_values: Vec<u64> = scores.iter().map(|(_, score)| *score).collect();
+            let (payouts, remainder_u128) = split_reward_pot(pot_u128, &score_values);
+            for ((provider, _), reward_u128) in scores.iter().zip(payouts) {
+                if reward_u128 == 0 {
+                    continue;
+                }
+                T::Currency::deposit_creating(provider, reward_u128.saturated_into());
+            }
+
+            let remainder: BalanceOf<T> = remainder_u128.saturated_into();
+            RewardRemainder::<T>::insert(subnet_owner, remainder);
+
+            Self::deposit_event(Event::RewardsDistributed(subnet_owner.clone(), pot));
+            T::WeightInfo::settle_subnet(providers.len() as u32)
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        // Settles one elapsed timeslice of a single lease's revenue, if its period has
+        // come due, paying the provider (minus the protocol fee) directly out of the
+        // consumer's reserved balance. Returns whether a timeslice was settled.
+        pub(crate) fn settle_lease(
+            provider: &T::AccountId,
+            consumer: &T::AccountId,
+            lease: &mut Lease<T>,
+            now: T::BlockNumber,
+        ) -> bool {
+            let period = T::TimeslicePeriod::get();
+            if period.is_zero() || now.saturating_sub(lease.last_settled_at) < period {
+                return false;
+            }
+
+            let cores: BalanceOf<T> = (lease.cores as u128).saturated_into();
+            let amount = lease.price_per_timeslice.saturating_mul(cores);
+            let fee = T::ProtocolFeePercent::get() * amount;
+            let provider_share = amount.saturating_sub(fee);
+
+            // `repatriate_reserved` returns the shortfall it couldn't move (e.g. the
+            // consumer's reserved balance came up short), not an all-or-nothing result.
+            // Account for only what was actually transferred, rather than the amount asked
+            // for, so `RevenueSettled` never reports a payment that didn't happen.
+            let shortfall = T::Currency::repatriate_reserved(consumer, provider, provider_share, BalanceStatus::Free)
+                .unwrap_or(provider_share);
+            let transferred = provider_share.saturating_sub(shortfall);
+
+            if !fee.is_zero() {
+                let (imbalance, _remainder) = T::Currency::slash_reserved(consumer, fee);
+                T::OnRevenue::on_unbalanced(imbalance);
+            }
+
+            lease.last_settled_at = lease.last_settled_at.saturating_add(period);
+            lease.remaining_timeslices = lease.remaining_timeslices.saturating_sub(1);
+
+            Self::deposit_event(Event::RevenueSettled(provider.clone(), consumer.clone(), transferred));
+            true
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<T::BlockNumber> for Pallet<T>
+    where
+        BalanceOf<T>: From<u32>,
+    {
+        // Settles subnets whose reward period has elapsed, and lease timeslices that have
+        // come due, as of this block, examining at most `MaxSubnetSettlementsPerBlock`
+        // subnets and `MaxLeaseSettlementsPerBlock` leases per block.
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            let mut weight = Weight::zero();
+
+            let period = T::RewardPeriod::get();
+            if !period.is_zero() {
+                // Bound how many subnets this hook examines per block, and charge the read
+                // cost of each one examined whether or not it turns out to be due for
+                // settlement, so the hook's weight can't outrun what's declared for it.
+                // Resume from wherever the previous block's scan left off (wrapping back to
+                // the start once the map is exhausted), so the scan advances round-robin
+                // across all subnets instead of always favouring the same fixed prefix.
+                let max_subnets = T::MaxSubnetSettlementsPerBlock::get() as usize;
+                let mut iter = match SubnetSettlementCursor::<T>::get() {
+                    Some(cursor) => Subnets::<T>::iter_from(cursor),
+                    None => Subnets::<T>::iter(),
+                };
+
+                let mut next_cursor = None;
+                for _ in 0..max_subnets {
+                    let (subnet_owner, subnet) = match iter.next() {
+                        Some(entry) => entry,
+                        // Reached the end of the map; wrap around next block.
+                        None => {
+                            next_cursor = None;
+                            break;
+                        }
+                    };
+
+                    let last = LastSettlement::<T>::get(&subnet_owner);
+                    weight = weight.saturating_add(T::DbWeight::get().reads(2));
+                    if now.saturating_sub(last) >= period {
+                        weight = weight.saturating_add(Self::settle_subnet(&subnet_owner, &subnet, now));
+                    }
+                    next_cursor = Some(iter.last_raw_key().to_vec());
+                }
+                SubnetSettlementCursor::<T>::set(next_cursor);
+            }
+
+            // Bound how many leases this hook examines per block, so its weight can't grow
+            // without limit as the number of active leases grows. Resume from wherever the
+            // previous block's scan left off (wrapping back to the start once the map is
+            // exhausted), so the scan advances round-robin across all leases instead of
+            // always favouring the same fixed prefix.
+            let max_leases = T::MaxLeaseSettlementsPerBlock::get() as usize;
+            let mut lease_iter = match LeaseSettlementCursor::<T>::get() {
+                Some(cursor) => Leases::<T>::iter_from(cursor),
+                None => Leases::<T>::iter(),
+            };
+
+            let mut lease_keys = Vec::new();
+            let mut next_lease_cursor = None;
+            for _ in 0..max_leases {
+                let (provider, consumer, _) = match lease_iter.next() {
+                    Some(entry) => entry,
+                    // Reached the end of the map; wrap around next block.
+                    None => {
+                        next_lease_cursor = None;
+                        break;
+                    }
+                };
+                next_lease_cursor = Some(lease_iter.last_raw_key().to_vec());
+                lease_keys.push((provider, consumer));
+            }
+            LeaseSettlementCursor::<T>::set(next_lease_cursor);
+
+            for (provider, consumer) in lease_keys {
+                Leases::<T>::mutate_exists(&provider, &consumer, |maybe_lease| {
+                    if let Some(lease) = maybe_lease {
+                        if Self::settle_lease(&provider, &consumer, lease, now) && lease.remaining_timeslices == 0 {
+                            let freed_cores = lease.cores;
+                            *maybe_lease = None;
+                            CapacityListings::<T>::mutate(&provider, |maybe_listing| {
+                                if let Some(listing) = maybe_listing {
+                                    listing.leased_cores = listing.leased_cores.saturating_sub(freed_cores);
+                                }
+                            });
+                        }
+                    }
+                });
+                weight = weight.saturating_add(T::WeightInfo::settle_lease());
+            }
+
+            weight
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,65 @@
+//! Pluggable pricing for the capacity-leasing marketplace.
+
+use sp_runtime::traits::{AtLeast32BitUnsigned, CheckedDiv};
+use sp_std::marker::PhantomData;
+
+/// Computes the price of a timeslice of leased capacity given a listing's base price,
+/// a configured slope, and how utilized the listing currently is.
+pub trait PriceAdapter<Balance> {
+    /// `utilization_numerator` / `utilization_denominator` is the fraction of a listing's
+    /// cores that are leased (after accounting for the lease being priced).
+    fn price(
+        base: Balance,
+        slope: Balance,
+        utilization_numerator: u32,
+        utilization_denominator: u32,
+    ) -> Balance;
+}
+
+/// The default `PriceAdapter`: `price = base + slope * utilization`.
+pub struct Linear<Balance>(PhantomData<Balance>);
+
+impl<Balance: AtLeast32BitUnsigned + Clone> PriceAdapter<Balance> for Linear<Balance> {
+    fn price(
+        base: Balance,
+        slope: Balance,
+        utilization_numerator: u32,
+        utilization_denominator: u32,
+    ) -> Balance {
+        if utilization_denominator == 0 {
+            return base;
+        }
+
+        let markup = slope
+            .saturating_mul(utilization_numerator.into())
+            .checked_div(&utilization_denominator.into())
+            .unwrap_or_else(Balance::zero);
+
+        base.saturating_add(markup)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Linear, PriceAdapter};
+
+    #[test]
+    fn zero_utilization_charges_just_the_base_price() {
+        assert_eq!(Linear::<u128>::price(100, 50, 0, 10), 100);
+    }
+
+    #[test]
+    fn full_utilization_adds_the_whole_slope() {
+        assert_eq!(Linear::<u128>::price(100, 50, 10, 10), 150);
+    }
+
+    #[test]
+    fn partial_utilization_adds_a_proportional_markup() {
+        assert_eq!(Linear::<u128>::price(100, 50, 5, 10), 125);
+    }
+
+    #[test]
+    fn zero_denominator_charges_just_the_base_price() {
+        assert_eq!(Linear::<u128>::price(100, 50, 0, 0), 100);
+    }
+}
@@ -0,0 +1,192 @@
+// This file is part of the pallet-subnet crate.
+
+//! Weights for pallet_subnet
+//!
+//! Manually written placeholder weights, in the shape the Substrate benchmark CLI
+//! produces. This tree has no `Cargo.toml` to run `frame-benchmarking-cli` against;
+//! replace these with real numbers from a benchmark run before using this pallet
+//! in a production runtime.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_subnet.
+pub trait WeightInfo {
+    fn create_subnet() -> Weight;
+    fn register_provider(p: u32) -> Weight;
+    fn deregister_provider(p: u32) -> Weight;
+    fn withdraw_unbonded() -> Weight;
+    fn slash_provider(p: u32) -> Weight;
+    fn report_performance() -> Weight;
+    fn settle_subnet(p: u32) -> Weight;
+    fn announce_key() -> Weight;
+    fn revoke_key() -> Weight;
+    fn list_capacity() -> Weight;
+    fn lease_capacity() -> Weight;
+    fn settle_lease() -> Weight;
+}
+
+/// Weights for pallet_subnet using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    // Storage: Subnet Subnets (r:1 w:1)
+    fn create_subnet() -> Weight {
+        Weight::from_ref_time(15_000_000 as u64)
+            .saturating_add(T::DbWeight::get().reads(1 as u64))
+            .saturating_add(T::DbWeight::get().writes(1 as u64))
+    }
+    // Storage: Subnet Subnets (r:1 w:0)
+    // Storage: Subnet Providers (r:1 w:1)
+    /// The range of component `p` is `[0, 1000]`.
+    fn register_provider(p: u32) -> Weight {
+        Weight::from_ref_time(16_000_000 as u64)
+            // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(25_000 as u64).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().reads(2 as u64))
+            .saturating_add(T::DbWeight::get().writes(1 as u64))
+    }
+    // Storage: Subnet Providers (r:1 w:1)
+    // Storage: Subnet Unbonding (r:1 w:1)
+    /// The range of component `p` is `[0, 1000]`.
+    fn deregister_provider(p: u32) -> Weight {
+        Weight::from_ref_time(17_000_000 as u64)
+            .saturating_add(Weight::from_ref_time(20_000 as u64).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().reads(2 as u64))
+            .saturating_add(T::DbWeight::get().writes(2 as u64))
+    }
+    // Storage: Subnet Unbonding (r:1 w:1)
+    fn withdraw_unbonded() -> Weight {
+        Weight::from_ref_time(14_000_000 as u64)
+            .saturating_add(T::DbWeight::get().reads(1 as u64))
+            .saturating_add(T::DbWeight::get().writes(1 as u64))
+    }
+    // Storage: Subnet Providers (r:1 w:1)
+    /// The range of component `p` is `[0, 1000]`.
+    fn slash_provider(p: u32) -> Weight {
+        Weight::from_ref_time(16_000_000 as u64)
+            .saturating_add(Weight::from_ref_time(20_000 as u64).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().reads(1 as u64))
+            .saturating_add(T::DbWeight::get().writes(1 as u64))
+    }
+    // Storage: Subnet Providers (r:1 w:0)
+    // Storage: Subnet ProviderPerformance (r:0 w:1)
+    fn report_performance() -> Weight {
+        Weight::from_ref_time(13_000_000 as u64)
+            .saturating_add(T::DbWeight::get().reads(1 as u64))
+            .saturating_add(T::DbWeight::get().writes(1 as u64))
+    }
+    // Storage: Subnet Providers (r:1 w:0)
+    // Storage: Subnet ProviderPerformance (r:p w:0)
+    // Storage: Subnet RewardRemainder (r:1 w:1)
+    // Storage: Subnet LastSettlement (r:0 w:1)
+    /// The range of component `p` is `[0, 1000]`.
+    fn settle_subnet(p: u32) -> Weight {
+        Weight::from_ref_time(18_000_000 as u64)
+            .saturating_add(Weight::from_ref_time(30_000 as u64).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().reads(2 as u64).saturating_add(T::DbWeight::get().reads(p as u64)))
+            .saturating_add(T::DbWeight::get().writes(2 as u64))
+    }
+    // Storage: Subnet ProviderKeys (r:0 w:1)
+    // Storage: Subnet RevokedKeys (r:0 w:1)
+    fn announce_key() -> Weight {
+        Weight::from_ref_time(12_000_000 as u64)
+            .saturating_add(T::DbWeight::get().writes(2 as u64))
+    }
+    // Storage: Subnet ProviderKeys (r:1 w:1)
+    // Storage: Subnet RevokedKeys (r:0 w:1)
+    fn revoke_key() -> Weight {
+        Weight::from_ref_time(12_000_000 as u64)
+            .saturating_add(T::DbWeight::get().reads(1 as u64))
+            .saturating_add(T::DbWeight::get().writes(2 as u64))
+    }
+    // Storage: Subnet CapacityListings (r:1 w:1)
+    fn list_capacity() -> Weight {
+        Weight::from_ref_time(14_000_000 as u64)
+            .saturating_add(T::DbWeight::get().reads(1 as u64))
+            .saturating_add(T::DbWeight::get().writes(1 as u64))
+    }
+    // Storage: Subnet CapacityListings (r:1 w:1)
+    // Storage: Subnet Leases (r:1 w:1)
+    fn lease_capacity() -> Weight {
+        Weight::from_ref_time(18_000_000 as u64)
+            .saturating_add(T::DbWeight::get().reads(2 as u64))
+            .saturating_add(T::DbWeight::get().writes(2 as u64))
+    }
+    // Storage: Subnet Leases (r:1 w:1)
+    // Storage: Subnet CapacityListings (r:1 w:1)
+    fn settle_lease() -> Weight {
+        Weight::from_ref_time(20_000_000 as u64)
+            .saturating_add(T::DbWeight::get().reads(2 as u64))
+            .saturating_add(T::DbWeight::get().writes(2 as u64))
+    }
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    fn create_subnet() -> Weight {
+        Weight::from_ref_time(15_000_000 as u64)
+            .saturating_add(RocksDbWeight::get().reads(1 as u64))
+            .saturating_add(RocksDbWeight::get().writes(1 as u64))
+    }
+    fn register_provider(p: u32) -> Weight {
+        Weight::from_ref_time(16_000_000 as u64)
+            .saturating_add(Weight::from_ref_time(25_000 as u64).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().reads(2 as u64))
+            .saturating_add(RocksDbWeight::get().writes(1 as u64))
+    }
+    fn deregister_provider(p: u32) -> Weight {
+        Weight::from_ref_time(17_000_000 as u64)
+            .saturating_add(Weight::from_ref_time(20_000 as u64).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().reads(2 as u64))
+            .saturating_add(RocksDbWeight::get().writes(2 as u64))
+    }
+    fn withdraw_unbonded() -> Weight {
+        Weight::from_ref_time(14_000_000 as u64)
+            .saturating_add(RocksDbWeight::get().reads(1 as u64))
+            .saturating_add(RocksDbWeight::get().writes(1 as u64))
+    }
+    fn slash_provider(p: u32) -> Weight {
+        Weight::from_ref_time(16_000_000 as u64)
+            .saturating_add(Weight::from_ref_time(20_000 as u64).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().reads(1 as u64))
+            .saturating_add(RocksDbWeight::get().writes(1 as u64))
+    }
+    fn report_performance() -> Weight {
+        Weight::from_ref_time(13_000_000 as u64)
+            .saturating_add(RocksDbWeight::get().reads(1 as u64))
+            .saturating_add(RocksDbWeight::get().writes(1 as u64))
+    }
+    fn settle_subnet(p: u32) -> Weight {
+        Weight::from_ref_time(18_000_000 as u64)
+            .saturating_add(Weight::from_ref_time(30_000 as u64).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().reads(2 as u64).saturating_add(RocksDbWeight::get().reads(p as u64)))
+            .saturating_add(RocksDbWeight::get().writes(2 as u64))
+    }
+    fn announce_key() -> Weight {
+        Weight::from_ref_time(12_000_000 as u64)
+            .saturating_add(RocksDbWeight::get().writes(2 as u64))
+    }
+    fn revoke_key() -> Weight {
+        Weight::from_ref_time(12_000_000 as u64)
+            .saturating_add(RocksDbWeight::get().reads(1 as u64))
+            .saturating_add(RocksDbWeight::get().writes(2 as u64))
+    }
+    fn list_capacity() -> Weight {
+        Weight::from_ref_time(14_000_000 as u64)
+            .saturating_add(RocksDbWeight::get().reads(1 as u64))
+            .saturating_add(RocksDbWeight::get().writes(1 as u64))
+    }
+    fn lease_capacity() -> Weight {
+        Weight::from_ref_time(18_000_000 as u64)
+            .saturating_add(RocksDbWeight::get().reads(2 as u64))
+            .saturating_add(RocksDbWeight::get().writes(2 as u64))
+    }
+    fn settle_lease() -> Weight {
+        Weight::from_ref_time(20_000_000 as u64)
+            .saturating_add(RocksDbWeight::get().reads(2 as u64))
+            .saturating_add(RocksDbWeight::get().writes(2 as u64))
+    }
+}